@@ -0,0 +1,55 @@
+use ethers::types::{Bytes, U256};
+use serde::{Deserialize, Serialize};
+
+use super::PaymasterParams;
+
+pub const GAS_PER_PUBDATA_DEFAULT: u64 = 50_000;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Eip712Meta {
+    pub gas_per_pubdata: U256,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub factory_deps: Vec<Bytes>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_signature: Option<Bytes>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paymaster_params: Option<PaymasterParams>,
+}
+
+impl Default for Eip712Meta {
+    fn default() -> Self {
+        Self {
+            gas_per_pubdata: GAS_PER_PUBDATA_DEFAULT.into(),
+            factory_deps: Default::default(),
+            custom_signature: Default::default(),
+            paymaster_params: Default::default(),
+        }
+    }
+}
+
+impl Eip712Meta {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn gas_per_pubdata(mut self, gas_per_pubdata: U256) -> Self {
+        self.gas_per_pubdata = gas_per_pubdata;
+        self
+    }
+
+    pub fn factory_deps(mut self, factory_deps: Vec<Bytes>) -> Self {
+        self.factory_deps = factory_deps;
+        self
+    }
+
+    pub fn custom_signature(mut self, custom_signature: impl Into<Bytes>) -> Self {
+        self.custom_signature = Some(custom_signature.into());
+        self
+    }
+
+    pub fn paymaster_params(mut self, paymaster_params: PaymasterParams) -> Self {
+        self.paymaster_params = Some(paymaster_params);
+        self
+    }
+}
@@ -0,0 +1,108 @@
+mod meta;
+mod paymaster;
+mod transaction;
+mod transaction_request;
+
+pub use meta::Eip712Meta;
+pub use paymaster::PaymasterParams;
+pub use transaction::{Eip712Error, Eip712Transaction};
+pub use transaction_request::Eip712TransactionRequest;
+
+use ethers::{
+    types::{Address, Bytes},
+    utils::keccak256,
+};
+
+/// Hashes deployed bytecode the way the zkSync VM expects it: the first two
+/// bytes encode the bytecode length in 32-byte words, the next two are
+/// reserved (always zero), and the rest is the keccak256 digest.
+pub fn hash_bytecode(bytecode: &Bytes) -> Result<[u8; 32], Eip712Error> {
+    if bytecode.len() % 32 != 0 {
+        return Err(Eip712Error(
+            "bytecode length must be a multiple of 32".to_owned(),
+        ));
+    }
+
+    let bytecode_len_words = bytecode.len() / 32;
+    if bytecode_len_words > u16::MAX as usize {
+        return Err(Eip712Error("bytecode is too long".to_owned()));
+    }
+
+    let mut hash = keccak256(bytecode);
+    hash[0..2].copy_from_slice(&(bytecode_len_words as u16).to_be_bytes());
+    hash[2..4].copy_from_slice(&[0, 0]);
+
+    Ok(hash)
+}
+
+/// Precomputes the address a `ContractDeployer.create2` call will deploy to,
+/// so callers can know it ahead of time (counterfactual deployment) or
+/// re-derive it to check whether a deterministic deployment already exists.
+pub fn compute_create2_address(
+    sender: Address,
+    salt: [u8; 32],
+    bytecode: &Bytes,
+    constructor_input: &Bytes,
+) -> Result<Address, Eip712Error> {
+    let bytecode_hash = hash_bytecode(bytecode)?;
+    let constructor_input_hash = keccak256(constructor_input);
+
+    // zkSync hashes the literal, not the raw ASCII bytes.
+    let create2_prefix = keccak256(b"zksyncCreate2");
+
+    let mut preimage = Vec::with_capacity(32 * 5);
+    preimage.extend_from_slice(&create2_prefix);
+    preimage.extend_from_slice(&[0_u8; 12]);
+    preimage.extend_from_slice(sender.as_bytes());
+    preimage.extend_from_slice(&salt);
+    preimage.extend_from_slice(&bytecode_hash);
+    preimage.extend_from_slice(&constructor_input_hash);
+
+    let digest = keccak256(preimage);
+    Ok(Address::from_slice(&digest[12..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn hash_bytecode_of_a_single_zero_word() {
+        let bytecode = Bytes::from(vec![0_u8; 32]);
+
+        let hash = hash_bytecode(&bytecode).unwrap();
+
+        assert_eq!(
+            hash,
+            [
+                0x00, 0x01, 0x00, 0x00, 0x54, 0x8b, 0x62, 0xa8, 0xd6, 0x03, 0x45, 0xa9, 0x88,
+                0x38, 0x6f, 0xc8, 0x4b, 0xa6, 0xbc, 0x95, 0x48, 0x40, 0x08, 0xf6, 0x36, 0x2f,
+                0x93, 0x16, 0x0e, 0xf3, 0xe5, 0x63,
+            ]
+        );
+    }
+
+    #[test]
+    fn hash_bytecode_rejects_lengths_not_a_multiple_of_32() {
+        let bytecode = Bytes::from(vec![0_u8; 31]);
+
+        assert!(hash_bytecode(&bytecode).is_err());
+    }
+
+    #[test]
+    fn compute_create2_address_matches_a_known_vector() {
+        let sender = Address::from_str("0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266").unwrap();
+        let salt = [0_u8; 32];
+        let bytecode = Bytes::from(vec![0_u8; 32]);
+        let constructor_input = Bytes::default();
+
+        let address =
+            compute_create2_address(sender, salt, &bytecode, &constructor_input).unwrap();
+
+        assert_eq!(
+            address,
+            Address::from_str("0xFE11963f0352bBb2aa46286FF6810daA705594b3").unwrap()
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use ethers::{
+    abi::Token,
+    types::{Address, Bytes, U256},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::zks_utils::encode_call;
+
+/// Paymaster parameters embedded in `Eip712Meta`, letting a third party
+/// sponsor the gas of a zkSync transaction.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymasterParams {
+    pub paymaster: Address,
+    pub paymaster_input: Bytes,
+}
+
+impl PaymasterParams {
+    /// Builds the paymaster input for the general flow: `general(bytes input)`.
+    pub fn general(paymaster: Address, input: impl Into<Bytes>) -> Self {
+        let input: Bytes = input.into();
+        Self {
+            paymaster,
+            paymaster_input: encode_call("general(bytes)", &[Token::Bytes(input.to_vec())]),
+        }
+    }
+
+    /// Builds the paymaster input for the approval-based flow:
+    /// `approvalBased(address token, uint256 minAllowance, bytes innerInput)`.
+    pub fn approval_based(
+        paymaster: Address,
+        token: Address,
+        min_allowance: U256,
+        inner_input: impl Into<Bytes>,
+    ) -> Self {
+        let inner_input: Bytes = inner_input.into();
+        Self {
+            paymaster,
+            paymaster_input: encode_call(
+                "approvalBased(address,uint256,bytes)",
+                &[
+                    Token::Address(token),
+                    Token::Uint(min_allowance),
+                    Token::Bytes(inner_input.to_vec()),
+                ],
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::utils::hex;
+    use std::str::FromStr;
+
+    #[test]
+    fn general_encodes_the_general_bytes_selector_and_input() {
+        let paymaster = Address::from_str("0x1111111111111111111111111111111111111111").unwrap();
+
+        let params = PaymasterParams::general(paymaster, Bytes::from(b"hi".to_vec()));
+
+        assert_eq!(params.paymaster, paymaster);
+        assert_eq!(
+            params.paymaster_input.to_vec(),
+            hex::decode(
+                "8c5a3445\
+                 0000000000000000000000000000000000000000000000000000000000000020\
+                 0000000000000000000000000000000000000000000000000000000000000002\
+                 6869000000000000000000000000000000000000000000000000000000000000"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn approval_based_encodes_token_allowance_and_inner_input() {
+        let paymaster = Address::from_str("0x1111111111111111111111111111111111111111").unwrap();
+        let token = Address::from_str("0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266").unwrap();
+
+        let params = PaymasterParams::approval_based(
+            paymaster,
+            token,
+            U256::from(1000),
+            Bytes::from(b"yo".to_vec()),
+        );
+
+        assert_eq!(params.paymaster, paymaster);
+        assert_eq!(
+            params.paymaster_input.to_vec(),
+            hex::decode(
+                "949431dc\
+                 000000000000000000000000f39fd6e51aad88f6f4ce6ab8827279cfffb92266\
+                 00000000000000000000000000000000000000000000000000000000000003e8\
+                 0000000000000000000000000000000000000000000000000000000000000060\
+                 0000000000000000000000000000000000000000000000000000000000000002\
+                 796f000000000000000000000000000000000000000000000000000000000000"
+            )
+            .unwrap()
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use ethers::{
+    types::{Address, Bytes, U256},
+    utils::rlp::{Encodable, RlpStream},
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Eip712Error, Eip712Meta, Eip712Transaction};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Eip712TransactionRequest {
+    pub r#type: U256,
+    pub from: Address,
+    pub to: Address,
+    pub chain_id: U256,
+    pub nonce: U256,
+    pub gas_limit: U256,
+    pub gas_price: U256,
+    pub max_priority_fee_per_gas: U256,
+    pub max_fee_per_gas: U256,
+    pub value: U256,
+    pub data: Bytes,
+    pub custom_data: Eip712Meta,
+}
+
+impl Eip712TransactionRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn r#type(mut self, r#type: impl Into<U256>) -> Self {
+        self.r#type = r#type.into();
+        self
+    }
+
+    pub fn from(mut self, from: Address) -> Self {
+        self.from = from;
+        self
+    }
+
+    pub fn to(mut self, to: Address) -> Self {
+        self.to = to;
+        self
+    }
+
+    pub fn chain_id(mut self, chain_id: impl Into<U256>) -> Self {
+        self.chain_id = chain_id.into();
+        self
+    }
+
+    pub fn nonce(mut self, nonce: impl Into<U256>) -> Self {
+        self.nonce = nonce.into();
+        self
+    }
+
+    pub fn gas_limit(mut self, gas_limit: impl Into<U256>) -> Self {
+        self.gas_limit = gas_limit.into();
+        self
+    }
+
+    pub fn gas_price(mut self, gas_price: impl Into<U256>) -> Self {
+        self.gas_price = gas_price.into();
+        self
+    }
+
+    pub fn max_priority_fee_per_gas(mut self, max_priority_fee_per_gas: impl Into<U256>) -> Self {
+        self.max_priority_fee_per_gas = max_priority_fee_per_gas.into();
+        self
+    }
+
+    pub fn max_fee_per_gas(mut self, max_fee_per_gas: impl Into<U256>) -> Self {
+        self.max_fee_per_gas = max_fee_per_gas.into();
+        self
+    }
+
+    pub fn value(mut self, value: impl Into<U256>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    pub fn data(mut self, data: impl Into<Bytes>) -> Self {
+        self.data = data.into();
+        self
+    }
+
+    pub fn custom_data(mut self, custom_data: Eip712Meta) -> Self {
+        self.custom_data = custom_data;
+        self
+    }
+
+    /// RLP-encodes the request (including the `customSignature` set in
+    /// `custom_data`, if any), ready to be prefixed with the `EIP712_TX_TYPE`
+    /// byte and broadcast via `send_raw_transaction`. The encoding itself is
+    /// implemented on [`Eip712Transaction`], the typed-data struct this
+    /// request converts into.
+    pub fn rlp_unsigned(&self) -> Result<Bytes, Eip712Error> {
+        let transaction: Eip712Transaction = self.clone().try_into()?;
+
+        let mut stream = RlpStream::new();
+        transaction.rlp_append(&mut stream);
+
+        Ok(stream.out().to_vec().into())
+    }
+}
@@ -0,0 +1,58 @@
+use async_trait::async_trait;
+use ethers::{
+    providers::Middleware,
+    types::{Address, H256, U256},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::eip712::Eip712TransactionRequest;
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Fee {
+    pub gas_limit: U256,
+    pub max_fee_per_gas: U256,
+    pub max_priority_fee_per_gas: U256,
+    pub gas_per_pubdata_limit: U256,
+}
+
+/// The default bridge contracts deployed on L1, as returned by `zks_getBridgeContracts`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BridgeContracts {
+    pub l1_erc20_default_bridge: Address,
+    pub l2_erc20_default_bridge: Address,
+}
+
+/// The Merkle proof for an L2->L1 log, as returned by `zks_getL2ToL1LogProof`.
+/// `id` is the index of the log within the block, used as `_l2MessageIndex`
+/// when finalizing a withdrawal on L1.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct L2ToL1LogProof {
+    pub id: u64,
+    pub proof: Vec<H256>,
+    pub root: H256,
+}
+
+#[async_trait]
+pub trait ZKSProvider: Middleware {
+    async fn estimate_fee(
+        &self,
+        transaction: Eip712TransactionRequest,
+    ) -> Result<Fee, Self::Error>;
+
+    /// Address of the main zkSync diamond contract on L1.
+    async fn get_main_contract(&self) -> Result<Address, Self::Error>;
+
+    /// Addresses of the default L1<->L2 ERC-20 bridge contracts.
+    async fn get_bridge_contracts(&self) -> Result<BridgeContracts, Self::Error>;
+
+    /// Merkle proof for the L2->L1 log at `log_index` (defaults to the first
+    /// one) emitted by `tx_hash`, used to finalize a withdrawal on L1.
+    async fn get_l2_to_l1_log_proof(
+        &self,
+        tx_hash: H256,
+        log_index: Option<u64>,
+    ) -> Result<Option<L2ToL1LogProof>, Self::Error>;
+}
@@ -0,0 +1,17 @@
+use ethers::{
+    abi::{encode, Token},
+    types::Bytes,
+    utils::id,
+};
+
+pub const CONTRACT_DEPLOYER_ADDR: &str = "0x00000000000000000000000000000000008006";
+pub const L2_ETH_TOKEN_ADDR: &str = "0x000000000000000000000000000000000000800A";
+pub const EIP712_TX_TYPE: u8 = 0x71;
+pub const ERA_CHAIN_ID: u64 = 270;
+pub const ETH_CHAIN_ID: u64 = 9;
+
+/// ABI-encodes a call to `signature` the same way `ethers::contract::encode_function_data`
+/// would, without requiring a loaded `Abi::function` for simple, hand-rolled calls.
+pub fn encode_call(signature: &str, tokens: &[Token]) -> Bytes {
+    [&id(signature)[..], &encode(tokens)].concat().into()
+}
@@ -0,0 +1,34 @@
+use ethers::{
+    prelude::{
+        k256::{
+            ecdsa::{RecoveryId, Signature as RecoverableSignature},
+            schnorr::signature::hazmat::PrehashSigner,
+        },
+        ContractError,
+    },
+    providers::{Middleware, ProviderError},
+    signers::WalletError,
+};
+use thiserror::Error;
+
+use crate::eip712::Eip712Error;
+
+#[derive(Debug, Error)]
+pub enum ZKSWalletError<M, D>
+where
+    M: Middleware,
+    D: PrehashSigner<(RecoverableSignature, RecoveryId)>,
+{
+    #[error(transparent)]
+    ProviderError(#[from] ProviderError),
+    #[error(transparent)]
+    SignerError(#[from] WalletError),
+    #[error(transparent)]
+    Eip712Error(#[from] Eip712Error),
+    #[error(transparent)]
+    ContractError(#[from] ContractError<M>),
+    #[error("timed out waiting for the requested number of confirmations")]
+    Timeout,
+    #[error("{0}")]
+    CustomError(String),
+}
@@ -0,0 +1,5 @@
+mod errors;
+mod wallet;
+
+pub use errors::ZKSWalletError;
+pub use wallet::ZKSWallet;
@@ -1,8 +1,13 @@
 use super::ZKSWalletError;
 use crate::{
-    eip712::{hash_bytecode, Eip712Meta, Eip712Transaction, Eip712TransactionRequest},
+    eip712::{
+        hash_bytecode, Eip712Meta, Eip712Transaction, Eip712TransactionRequest, PaymasterParams,
+    },
     zks_provider::ZKSProvider,
-    zks_utils::{CONTRACT_DEPLOYER_ADDR, EIP712_TX_TYPE, ERA_CHAIN_ID, ETH_CHAIN_ID},
+    zks_utils::{
+        encode_call, CONTRACT_DEPLOYER_ADDR, EIP712_TX_TYPE, ERA_CHAIN_ID, ETH_CHAIN_ID,
+        L2_ETH_TOKEN_ADDR,
+    },
 };
 use ethers::{
     abi::{Abi, Token},
@@ -14,14 +19,28 @@ use ethers::{
         },
         ContractError, MiddlewareBuilder, SignerMiddleware,
     },
-    providers::Middleware,
+    providers::{JsonRpcClient, Middleware, PendingTransaction},
     signers::{Signer, Wallet},
     types::{
-        transaction::eip2718::TypedTransaction, Address, Bytes, Eip1559TransactionRequest,
+        transaction::eip2718::TypedTransaction, Address, Bytes, Eip1559TransactionRequest, H256,
         Signature, TransactionReceipt, U256,
     },
 };
-use std::{fs::File, io::BufReader, str::FromStr};
+use std::{fs::File, io::BufReader, str::FromStr, time::Duration};
+use tokio::sync::Mutex;
+
+/// Default number of confirmations to wait for, and the polling
+/// interval/timeout used while waiting, unless overridden with
+/// [`ZKSWallet::with_confirmations`] and friends.
+const DEFAULT_CONFIRMATIONS: usize = 1;
+const DEFAULT_POLLING_INTERVAL: Duration = Duration::from_millis(7_000);
+const DEFAULT_CONFIRMATION_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Gas limit used for the L2 side of a deposit when the caller doesn't have a
+/// more precise estimate; generous enough to cover bridge `finalizeDeposit`.
+const L2_DEPOSIT_GAS_LIMIT: u64 = 10_000_000;
+/// Default `gas_per_pubdata` used for the L2 side of a deposit.
+const L2_DEPOSIT_GAS_PER_PUBDATA_BYTE_LIMIT: u64 = 800;
 
 pub struct ZKSWallet<M, D>
 where
@@ -31,6 +50,16 @@ where
     pub eth_provider: Option<SignerMiddleware<M, Wallet<D>>>,
     pub era_provider: Option<SignerMiddleware<M, Wallet<D>>>,
     pub wallet: Wallet<D>,
+    // Cached nonce for outgoing EIP-712 transactions, so several transfers or
+    // deployments can be pipelined from the same wallet before the first one
+    // is mined without reusing a nonce. `None` means it hasn't been seeded
+    // from the node yet. Guarded by a real lock (rather than an atomic flag
+    // plus an independent atomic counter) so two concurrent first calls can't
+    // both seed the cache and hand out the same nonce.
+    nonce: Mutex<Option<u64>>,
+    confirmations: usize,
+    confirmation_interval: Duration,
+    confirmation_timeout: Duration,
 }
 
 impl<M, D> ZKSWallet<M, D>
@@ -48,9 +77,121 @@ where
             era_provider: era_provider
                 .map(|p| p.with_signer(wallet.clone().with_chain_id(ERA_CHAIN_ID))),
             eth_provider: eth_provider.map(|p| p.with_signer(wallet.with_chain_id(ETH_CHAIN_ID))),
+            nonce: Mutex::new(None),
+            confirmations: DEFAULT_CONFIRMATIONS,
+            confirmation_interval: DEFAULT_POLLING_INTERVAL,
+            confirmation_timeout: DEFAULT_CONFIRMATION_TIMEOUT,
         })
     }
 
+    /// Sets how many confirmations to wait for after broadcasting a
+    /// transaction. Defaults to `1`.
+    pub fn with_confirmations(mut self, confirmations: usize) -> Self {
+        self.confirmations = confirmations;
+        self
+    }
+
+    /// Sets how often to poll for the transaction's confirmation depth.
+    pub fn with_confirmation_interval(mut self, interval: Duration) -> Self {
+        self.confirmation_interval = interval;
+        self
+    }
+
+    /// Sets how long to wait for the requested confirmation depth before
+    /// giving up with [`ZKSWalletError::Timeout`].
+    pub fn with_confirmation_timeout(mut self, timeout: Duration) -> Self {
+        self.confirmation_timeout = timeout;
+        self
+    }
+
+    /// Awaits `pending_transaction` up to `self.confirmations` deep, polling
+    /// every `self.confirmation_interval` and giving up after
+    /// `self.confirmation_timeout` instead of hanging forever.
+    async fn wait_for_receipt<P>(
+        &self,
+        pending_transaction: PendingTransaction<'_, P>,
+    ) -> Result<TransactionReceipt, ZKSWalletError<M, D>>
+    where
+        P: JsonRpcClient,
+    {
+        self.wait_for_confirmations(pending_transaction, self.confirmations)
+            .await
+    }
+
+    /// Like [`wait_for_receipt`](Self::wait_for_receipt), but waits for
+    /// `confirmations` instead of the wallet-wide default set by
+    /// [`with_confirmations`](Self::with_confirmations). Combine with the
+    /// `*_pending` methods (e.g. [`transfer_pending`](Self::transfer_pending))
+    /// to request a one-off confirmation depth without changing the wallet's
+    /// default for every other call.
+    pub async fn wait_for_confirmations<P>(
+        &self,
+        pending_transaction: PendingTransaction<'_, P>,
+        confirmations: usize,
+    ) -> Result<TransactionReceipt, ZKSWalletError<M, D>>
+    where
+        P: JsonRpcClient,
+    {
+        tokio::time::timeout(
+            self.confirmation_timeout,
+            pending_transaction
+                .interval(self.confirmation_interval)
+                .confirmations(confirmations),
+        )
+        .await
+        .map_err(|_| ZKSWalletError::Timeout)??
+        .ok_or(ZKSWalletError::CustomError(
+            "no transaction receipt".to_owned(),
+        ))
+    }
+
+    /// Returns the next nonce to use for an outgoing EIP-712 transaction,
+    /// lazily seeding the cache from the node on first use and incrementing
+    /// it under the same lock so concurrent transfers/deployments don't
+    /// collide.
+    pub async fn next_nonce(&self) -> Result<U256, ZKSWalletError<M, D>>
+    where
+        M: ZKSProvider,
+    {
+        let mut cached_nonce = self.nonce.lock().await;
+        if cached_nonce.is_none() {
+            *cached_nonce = Some(self.fetch_nonce().await?);
+        }
+
+        let nonce = cached_nonce.as_mut().expect("seeded above");
+        let current = *nonce;
+        *nonce += 1;
+
+        Ok(current.into())
+    }
+
+    /// Resyncs the cached nonce from the node. Call this after a send fails
+    /// with a nonce error, or to seed a batch of pipelined sends up front.
+    pub async fn reset_nonce(&self) -> Result<(), ZKSWalletError<M, D>>
+    where
+        M: ZKSProvider,
+    {
+        let nonce = self.fetch_nonce().await?;
+        *self.nonce.lock().await = Some(nonce);
+
+        Ok(())
+    }
+
+    async fn fetch_nonce(&self) -> Result<u64, ZKSWalletError<M, D>>
+    where
+        M: ZKSProvider,
+    {
+        let era_provider = match &self.era_provider {
+            Some(era_provider) => era_provider,
+            None => return Err(ZKSWalletError::CustomError("no era provider".to_owned())),
+        };
+
+        Ok(era_provider
+            .get_transaction_count(self.address(), None)
+            .await?
+            .as_u64())
+    }
+
     pub fn connect_eth_provider(mut self, eth_provider: M) -> Self {
         self.eth_provider = Some(eth_provider.with_signer(self.wallet.clone()));
         self
@@ -105,12 +246,38 @@ where
         }
     }
 
+    /// Balance of the native coin (`token: None`) or an ERC-20 `token` on L2.
+    pub async fn balance_of(&self, token: Option<Address>) -> Result<U256, ZKSWalletError<M, D>>
+    where
+        M: ZKSProvider,
+    {
+        let token = match token {
+            None => return self.era_balance().await,
+            Some(token) => token,
+        };
+
+        let era_provider = match &self.era_provider {
+            Some(era_provider) => era_provider,
+            None => return Err(ZKSWalletError::CustomError("no era provider".to_owned())),
+        };
+
+        let call_request: TypedTransaction = Eip1559TransactionRequest::new()
+            .to(token)
+            .data(encode_call(
+                "balanceOf(address)",
+                &[Token::Address(self.address())],
+            ))
+            .into();
+        let result = era_provider.call(&call_request, None).await?;
+
+        Ok(U256::from_big_endian(&result))
+    }
+
     pub async fn transfer(
         &self,
         to: Address,
         amount_to_transfer: U256,
-        // TODO: Support multiple-token transfers.
-        _token: Option<Address>,
+        token: Option<Address>,
     ) -> Result<TransactionReceipt, ZKSWalletError<M, D>>
     where
         M: ZKSProvider,
@@ -120,10 +287,23 @@ where
             None => return Err(ZKSWalletError::CustomError("no era provider".to_owned())),
         };
 
+        let (call_to, value, data) = match token {
+            None => (to, amount_to_transfer, Bytes::default()),
+            Some(token) => (
+                token,
+                U256::zero(),
+                encode_call(
+                    "transfer(address,uint256)",
+                    &[Token::Address(to), Token::Uint(amount_to_transfer)],
+                ),
+            ),
+        };
+
         let mut transfer_request = Eip1559TransactionRequest::new()
             .from(self.address())
-            .to(to)
-            .value(amount_to_transfer)
+            .to(call_to)
+            .value(value)
+            .data(data)
             .chain_id(ERA_CHAIN_ID);
 
         let fee = era_provider.estimate_fee(transfer_request.clone()).await?;
@@ -135,22 +315,106 @@ where
         // TODO: add block as an override.
         let pending_transaction = era_provider.send_transaction(transaction, None).await?;
 
-        // TODO: Should we wait here for the transaction to be confirmed on-chain?
+        self.wait_for_receipt(pending_transaction).await
+    }
 
-        pending_transaction
-            .await?
-            .ok_or(ZKSWalletError::CustomError(
-                "no transaction receipt".to_owned(),
-            ))
+    /// Same as [`transfer`](Self::transfer), but returns as soon as the
+    /// transaction is broadcast instead of waiting for confirmations.
+    pub async fn transfer_pending(
+        &self,
+        to: Address,
+        amount_to_transfer: U256,
+        token: Option<Address>,
+    ) -> Result<PendingTransaction<'_, M::Provider>, ZKSWalletError<M, D>>
+    where
+        M: ZKSProvider,
+    {
+        let era_provider = match &self.era_provider {
+            Some(era_provider) => era_provider,
+            None => return Err(ZKSWalletError::CustomError("no era provider".to_owned())),
+        };
+
+        let (call_to, value, data) = match token {
+            None => (to, amount_to_transfer, Bytes::default()),
+            Some(token) => (
+                token,
+                U256::zero(),
+                encode_call(
+                    "transfer(address,uint256)",
+                    &[Token::Address(to), Token::Uint(amount_to_transfer)],
+                ),
+            ),
+        };
+
+        let mut transfer_request = Eip1559TransactionRequest::new()
+            .from(self.address())
+            .to(call_to)
+            .value(value)
+            .data(data)
+            .chain_id(ERA_CHAIN_ID);
+
+        let fee = era_provider.estimate_fee(transfer_request.clone()).await?;
+        transfer_request = transfer_request.max_priority_fee_per_gas(fee.max_priority_fee_per_gas);
+        transfer_request = transfer_request.max_fee_per_gas(fee.max_fee_per_gas);
+
+        Ok(era_provider
+            .send_transaction(transfer_request, None)
+            .await?)
     }
 
     pub async fn transfer_eip712(
         &self,
         to: Address,
         amount_to_transfer: U256,
-        // TODO: Support multiple-token transfers.
-        _token: Option<Address>,
+        token: Option<Address>,
+    ) -> Result<TransactionReceipt, ZKSWalletError<M, D>>
+    where
+        M: ZKSProvider,
+    {
+        self._transfer_eip712(to, amount_to_transfer, token, None)
+            .await
+    }
+
+    /// Same as [`transfer_eip712`](Self::transfer_eip712), but has a third party
+    /// (the paymaster) sponsor the gas for the transaction.
+    pub async fn transfer_eip712_with_paymaster(
+        &self,
+        to: Address,
+        amount_to_transfer: U256,
+        token: Option<Address>,
+        paymaster_params: PaymasterParams,
     ) -> Result<TransactionReceipt, ZKSWalletError<M, D>>
+    where
+        M: ZKSProvider,
+    {
+        self._transfer_eip712(to, amount_to_transfer, token, Some(paymaster_params))
+            .await
+    }
+
+    async fn _transfer_eip712(
+        &self,
+        to: Address,
+        amount_to_transfer: U256,
+        token: Option<Address>,
+        paymaster_params: Option<PaymasterParams>,
+    ) -> Result<TransactionReceipt, ZKSWalletError<M, D>>
+    where
+        M: ZKSProvider,
+    {
+        let pending_transaction = self
+            ._transfer_eip712_pending(to, amount_to_transfer, token, paymaster_params)
+            .await?;
+
+        self.wait_for_receipt(pending_transaction).await
+    }
+
+    async fn _transfer_eip712_pending(
+        &self,
+        to: Address,
+        amount_to_transfer: U256,
+        token: Option<Address>,
+        paymaster_params: Option<PaymasterParams>,
+    ) -> Result<PendingTransaction<'_, M::Provider>, ZKSWalletError<M, D>>
     where
         M: ZKSProvider,
     {
@@ -159,17 +423,36 @@ where
             None => return Err(ZKSWalletError::CustomError("no era provider".to_owned())),
         };
 
+        let (call_to, value, data) = match token {
+            None => (to, amount_to_transfer, Bytes::default()),
+            Some(token) => (
+                token,
+                U256::zero(),
+                encode_call(
+                    "transfer(address,uint256)",
+                    &[Token::Address(to), Token::Uint(amount_to_transfer)],
+                ),
+            ),
+        };
+
+        let mut custom_data = Eip712Meta::new();
+        if let Some(paymaster_params) = paymaster_params {
+            custom_data = custom_data.paymaster_params(paymaster_params);
+        }
+
         let mut transfer_request = Eip712TransactionRequest::new()
+            .r#type(EIP712_TX_TYPE)
             .from(self.address())
-            .to(to)
-            .value(amount_to_transfer)
-            .nonce(
-                era_provider
-                    .get_transaction_count(self.address(), None)
-                    .await?,
-            )
-            .gas_price(era_provider.get_gas_price().await?);
+            .to(call_to)
+            .value(value)
+            .data(data)
+            .chain_id(ERA_CHAIN_ID)
+            .nonce(self.next_nonce().await?)
+            .gas_price(era_provider.get_gas_price().await?)
+            .custom_data(custom_data.clone());
 
+        // The paymaster is part of the fee-estimation request so the returned
+        // gas limits account for its validation and execution.
         let fee = era_provider.estimate_fee(transfer_request.clone()).await?;
         transfer_request = transfer_request
             .max_priority_fee_per_gas(fee.max_priority_fee_per_gas)
@@ -179,33 +462,117 @@ where
         let signable_data: Eip712Transaction = transfer_request.clone().try_into()?;
         let signature: Signature = self.wallet.sign_typed_data(&signable_data).await?;
         transfer_request =
-            transfer_request.custom_data(Eip712Meta::new().custom_signature(signature.to_vec()));
+            transfer_request.custom_data(custom_data.custom_signature(signature.to_vec()));
+
+        let raw_transaction: Bytes = [&[EIP712_TX_TYPE], &*transfer_request.rlp_unsigned()?]
+            .concat()
+            .into();
+
+        match era_provider.send_raw_transaction(raw_transaction).await {
+            Ok(pending_transaction) => Ok(pending_transaction),
+            Err(error) => {
+                // The cached nonce may be stale (e.g. a previous pipelined
+                // send never made it to the mempool); resync before failing.
+                // Ignore resync failures so the original send error (the one
+                // the caller actually needs to see) isn't discarded.
+                let _ = self.reset_nonce().await;
+                Err(error.into())
+            }
+        }
+    }
 
-        let pending_transaction = era_provider
-            .send_raw_transaction(
-                [&[EIP712_TX_TYPE], &*transfer_request.rlp_unsigned()]
-                    .concat()
-                    .into(),
+    /// Same as [`transfer_eip712`](Self::transfer_eip712), but returns as soon
+    /// as the transaction is broadcast instead of waiting for confirmations.
+    pub async fn transfer_eip712_pending(
+        &self,
+        to: Address,
+        amount_to_transfer: U256,
+        token: Option<Address>,
+    ) -> Result<PendingTransaction<'_, M::Provider>, ZKSWalletError<M, D>>
+    where
+        M: ZKSProvider,
+    {
+        self._transfer_eip712_pending(to, amount_to_transfer, token, None)
+            .await
+    }
+
+    pub async fn deploy(
+        &self,
+        contract_abi: Abi,
+        contract_bytecode: Bytes,
+        contract_dependencies: Option<Vec<Bytes>>,
+        constructor_parameters: Vec<Token>,
+    ) -> Result<Address, ZKSWalletError<M, D>>
+    where
+        M: ZKSProvider,
+    {
+        let transaction_receipt = self
+            ._deploy(
+                contract_abi,
+                contract_bytecode,
+                contract_dependencies,
+                constructor_parameters,
+                None,
+                None,
             )
             .await?;
 
-        // TODO: Should we wait here for the transaction to be confirmed on-chain?
+        let contract_address =
+            transaction_receipt
+                .contract_address
+                .ok_or(ZKSWalletError::CustomError(
+                    "no contract address".to_owned(),
+                ))?;
 
-        let transaction_receipt = pending_transaction
-            .await?
-            .ok_or(ZKSWalletError::CustomError(
-                "no transaction receipt".to_owned(),
-            ))?;
+        Ok(contract_address)
+    }
+
+    /// Same as [`deploy`](Self::deploy), but has a third party (the paymaster)
+    /// sponsor the gas for the deployment.
+    pub async fn deploy_with_paymaster(
+        &self,
+        contract_abi: Abi,
+        contract_bytecode: Bytes,
+        contract_dependencies: Option<Vec<Bytes>>,
+        constructor_parameters: Vec<Token>,
+        paymaster_params: PaymasterParams,
+    ) -> Result<Address, ZKSWalletError<M, D>>
+    where
+        M: ZKSProvider,
+    {
+        let transaction_receipt = self
+            ._deploy(
+                contract_abi,
+                contract_bytecode,
+                contract_dependencies,
+                constructor_parameters,
+                None,
+                Some(paymaster_params),
+            )
+            .await?;
 
-        Ok(transaction_receipt)
+        let contract_address =
+            transaction_receipt
+                .contract_address
+                .ok_or(ZKSWalletError::CustomError(
+                    "no contract address".to_owned(),
+                ))?;
+
+        Ok(contract_address)
     }
 
-    pub async fn deploy(
+    /// Deploys the contract deterministically via `ContractDeployer.create2`,
+    /// so the resulting address only depends on the deployer, `salt`, the
+    /// bytecode and the constructor arguments. See
+    /// [`compute_create2_address`](crate::eip712::compute_create2_address) to
+    /// derive that address ahead of time.
+    pub async fn deploy_create2(
         &self,
         contract_abi: Abi,
         contract_bytecode: Bytes,
         contract_dependencies: Option<Vec<Bytes>>,
         constructor_parameters: Vec<Token>,
+        salt: [u8; 32],
     ) -> Result<Address, ZKSWalletError<M, D>>
     where
         M: ZKSProvider,
@@ -216,6 +583,8 @@ where
                 contract_bytecode,
                 contract_dependencies,
                 constructor_parameters,
+                Some(salt),
+                None,
             )
             .await?;
 
@@ -245,6 +614,8 @@ where
                 contract_bytecode,
                 contract_dependencies,
                 constructor_parameters,
+                None,
+                None,
             )
             .await?;
 
@@ -266,6 +637,8 @@ where
         contract_bytecode: Bytes,
         contract_dependencies: Option<Vec<Bytes>>,
         constructor_parameters: Vec<Token>,
+        create2_salt: Option<[u8; 32]>,
+        paymaster_params: Option<PaymasterParams>,
     ) -> Result<TransactionReceipt, ZKSWalletError<M, D>>
     where
         M: ZKSProvider,
@@ -275,13 +648,16 @@ where
             None => return Err(ZKSWalletError::CustomError("no era provider".to_owned())),
         };
 
-        let custom_data = Eip712Meta::new().factory_deps({
+        let mut custom_data = Eip712Meta::new().factory_deps({
             let mut factory_deps = vec![contract_bytecode.clone()];
             if let Some(contract_dependencies) = contract_dependencies {
                 factory_deps.extend(contract_dependencies);
             }
             factory_deps
         });
+        if let Some(paymaster_params) = paymaster_params {
+            custom_data = custom_data.paymaster_params(paymaster_params);
+        }
 
         let mut deploy_request = Eip712TransactionRequest::new()
             .r#type(EIP712_TX_TYPE)
@@ -290,20 +666,21 @@ where
                 ZKSWalletError::CustomError(format!("invalid contract deployer address: {e}"))
             })?)
             .chain_id(ERA_CHAIN_ID)
-            .nonce(
-                era_provider
-                    .get_transaction_count(self.address(), None)
-                    .await?,
-            )
+            .nonce(self.next_nonce().await?)
             .gas_price(era_provider.get_gas_price().await?)
             .data({
                 let contract_deployer = Abi::load(BufReader::new(
                     File::open("./src/abi/ContractDeployer.json").unwrap(),
                 ))
                 .unwrap();
-                let create = contract_deployer.function("create").unwrap();
-                // TODO: User could provide this instead of defaulting.
-                let salt = [0_u8; 32];
+                let salt = create2_salt.unwrap_or([0_u8; 32]);
+                let deployer_function = contract_deployer
+                    .function(if create2_salt.is_some() {
+                        "create2"
+                    } else {
+                        "create"
+                    })
+                    .unwrap();
                 let bytecode_hash = hash_bytecode(&contract_bytecode)?;
                 let call_data: Bytes = match (
                     contract_abi.constructor(),
@@ -317,7 +694,7 @@ where
                         .into(),
                 };
 
-                encode_function_data(create, (salt, bytecode_hash, call_data))?
+                encode_function_data(deployer_function, (salt, bytecode_hash, call_data))?
             })
             .custom_data(custom_data.clone());
 
@@ -332,21 +709,289 @@ where
         deploy_request =
             deploy_request.custom_data(custom_data.custom_signature(signature.to_vec()));
 
-        let pending_transaction = era_provider
-            .send_raw_transaction(
-                [&[EIP712_TX_TYPE], &*deploy_request.rlp_unsigned()]
-                    .concat()
-                    .into(),
-            )
-            .await?;
+        let raw_transaction: Bytes = [&[EIP712_TX_TYPE], &*deploy_request.rlp_unsigned()?]
+            .concat()
+            .into();
+
+        let pending_transaction = match era_provider.send_raw_transaction(raw_transaction).await {
+            Ok(pending_transaction) => pending_transaction,
+            Err(error) => {
+                // The cached nonce may be stale (e.g. a previous pipelined
+                // send never made it to the mempool); resync before failing.
+                // Ignore resync failures so the original send error (the one
+                // the caller actually needs to see) isn't discarded.
+                let _ = self.reset_nonce().await;
+                return Err(error.into());
+            }
+        };
+
+        self.wait_for_receipt(pending_transaction).await
+    }
+
+    /// Bridges `amount` of `token` (or ETH, for `None`) from L1 to `to` on L2
+    /// by calling the zkSync L1 contracts through `eth_provider`.
+    pub async fn deposit(
+        &self,
+        token: Option<Address>,
+        amount: U256,
+        to: Address,
+    ) -> Result<TransactionReceipt, ZKSWalletError<M, D>>
+    where
+        M: ZKSProvider,
+    {
+        let eth_provider = match &self.eth_provider {
+            Some(eth_provider) => eth_provider,
+            None => return Err(ZKSWalletError::CustomError("no eth provider".to_owned())),
+        };
+
+        match token {
+            None => self.deposit_eth(eth_provider, amount, to).await,
+            Some(token) => self.deposit_erc20(eth_provider, token, amount, to).await,
+        }
+    }
+
+    async fn deposit_eth(
+        &self,
+        eth_provider: &SignerMiddleware<M, Wallet<D>>,
+        amount: U256,
+        to: Address,
+    ) -> Result<TransactionReceipt, ZKSWalletError<M, D>>
+    where
+        M: ZKSProvider,
+    {
+        let main_contract = eth_provider.get_main_contract().await?;
+        let base_cost = self.l2_transaction_base_cost(eth_provider, main_contract).await?;
+
+        let call_data = encode_call(
+            "requestL2Transaction(address,uint256,bytes,uint256,uint256,bytes[],address)",
+            &[
+                Token::Address(to),
+                Token::Uint(amount),
+                Token::Bytes(Vec::new()),
+                Token::Uint(L2_DEPOSIT_GAS_LIMIT.into()),
+                Token::Uint(L2_DEPOSIT_GAS_PER_PUBDATA_BYTE_LIMIT.into()),
+                Token::Array(Vec::new()),
+                Token::Address(self.address()),
+            ],
+        );
+
+        let deposit_request = Eip1559TransactionRequest::new()
+            .from(self.address())
+            .to(main_contract)
+            .value(base_cost + amount)
+            .data(call_data)
+            .chain_id(ETH_CHAIN_ID);
+
+        let pending_transaction = eth_provider.send_transaction(deposit_request, None).await?;
+
+        self.wait_for_receipt(pending_transaction).await
+    }
+
+    async fn deposit_erc20(
+        &self,
+        eth_provider: &SignerMiddleware<M, Wallet<D>>,
+        token: Address,
+        amount: U256,
+        to: Address,
+    ) -> Result<TransactionReceipt, ZKSWalletError<M, D>>
+    where
+        M: ZKSProvider,
+    {
+        let l1_bridge = eth_provider.get_bridge_contracts().await?.l1_erc20_default_bridge;
+
+        // The bridge pulls the tokens with `transferFrom`, so it must be approved first.
+        let approve_request = Eip1559TransactionRequest::new()
+            .from(self.address())
+            .to(token)
+            .data(encode_call(
+                "approve(address,uint256)",
+                &[Token::Address(l1_bridge), Token::Uint(amount)],
+            ))
+            .chain_id(ETH_CHAIN_ID);
+        let pending_approval = eth_provider.send_transaction(approve_request, None).await?;
+        self.wait_for_receipt(pending_approval).await?;
+
+        let main_contract = eth_provider.get_main_contract().await?;
+        let base_cost = self.l2_transaction_base_cost(eth_provider, main_contract).await?;
+
+        let deposit_request = Eip1559TransactionRequest::new()
+            .from(self.address())
+            .to(l1_bridge)
+            .value(base_cost)
+            .data(encode_call(
+                "deposit(address,address,uint256,uint256,uint256,address)",
+                &[
+                    Token::Address(to),
+                    Token::Address(token),
+                    Token::Uint(amount),
+                    Token::Uint(L2_DEPOSIT_GAS_LIMIT.into()),
+                    Token::Uint(L2_DEPOSIT_GAS_PER_PUBDATA_BYTE_LIMIT.into()),
+                    Token::Address(self.address()),
+                ],
+            ))
+            .chain_id(ETH_CHAIN_ID);
+
+        let pending_transaction = eth_provider.send_transaction(deposit_request, None).await?;
+
+        self.wait_for_receipt(pending_transaction).await
+    }
 
-        // TODO: Should we wait here for the transaction to be confirmed on-chain?
+    /// Calls the main contract's `l2TransactionBaseCost` view so the deposit's
+    /// `msg.value` covers the L2 operator fee in addition to the bridged amount.
+    async fn l2_transaction_base_cost(
+        &self,
+        eth_provider: &SignerMiddleware<M, Wallet<D>>,
+        main_contract: Address,
+    ) -> Result<U256, ZKSWalletError<M, D>>
+    where
+        M: ZKSProvider,
+    {
+        let gas_price = eth_provider.get_gas_price().await?;
+        let call_request = Eip1559TransactionRequest::new().to(main_contract).data(encode_call(
+            "l2TransactionBaseCost(uint256,uint256,uint256)",
+            &[
+                Token::Uint(gas_price),
+                Token::Uint(L2_DEPOSIT_GAS_LIMIT.into()),
+                Token::Uint(L2_DEPOSIT_GAS_PER_PUBDATA_BYTE_LIMIT.into()),
+            ],
+        ));
+
+        let result = eth_provider.call(&call_request.into(), None).await?;
+        Ok(U256::from_big_endian(&result))
+    }
 
-        pending_transaction
+    /// Bridges `amount` of `token` (or ETH, for `None`) from L2 to `to` on L1.
+    /// The withdrawal only becomes claimable on L1 once it is finalized with
+    /// [`finalize_withdrawal`](Self::finalize_withdrawal).
+    pub async fn withdraw(
+        &self,
+        token: Option<Address>,
+        amount: U256,
+        to: Address,
+    ) -> Result<TransactionReceipt, ZKSWalletError<M, D>>
+    where
+        M: ZKSProvider,
+    {
+        let era_provider = match &self.era_provider {
+            Some(era_provider) => era_provider,
+            None => return Err(ZKSWalletError::CustomError("no era provider".to_owned())),
+        };
+
+        // ETH and ERC-20 withdrawals share this API but serialize their
+        // arguments differently, so branch just on the target, value and call data.
+        let (withdraw_to, value, call_data) = match token {
+            None => (
+                Address::from_str(L2_ETH_TOKEN_ADDR).map_err(|e| {
+                    ZKSWalletError::CustomError(format!("invalid L2 ETH token address: {e}"))
+                })?,
+                amount,
+                encode_call("withdraw(address)", &[Token::Address(to)]),
+            ),
+            Some(token) => {
+                let l2_bridge = era_provider.get_bridge_contracts().await?.l2_erc20_default_bridge;
+                (
+                    l2_bridge,
+                    U256::zero(),
+                    encode_call(
+                        "withdraw(address,address,uint256)",
+                        &[Token::Address(to), Token::Address(token), Token::Uint(amount)],
+                    ),
+                )
+            }
+        };
+
+        let withdraw_request = Eip1559TransactionRequest::new()
+            .from(self.address())
+            .to(withdraw_to)
+            .value(value)
+            .data(call_data)
+            .chain_id(ERA_CHAIN_ID);
+
+        let pending_transaction = era_provider.send_transaction(withdraw_request, None).await?;
+
+        self.wait_for_receipt(pending_transaction).await
+    }
+
+    /// Completes a withdrawal started by [`withdraw`](Self::withdraw) by
+    /// fetching its L2->L1 log and Merkle proof and calling the matching
+    /// `finalize*Withdrawal` method on L1. `token` must be the same one
+    /// passed to `withdraw` (or `None` for ETH) — every L2 transaction emits
+    /// a base-token transfer log to pay its gas fee, so the token type can't
+    /// be guessed back out of the receipt and has to be threaded through.
+    pub async fn finalize_withdrawal(
+        &self,
+        l2_tx_hash: H256,
+        token: Option<Address>,
+    ) -> Result<TransactionReceipt, ZKSWalletError<M, D>>
+    where
+        M: ZKSProvider,
+    {
+        let eth_provider = match &self.eth_provider {
+            Some(eth_provider) => eth_provider,
+            None => return Err(ZKSWalletError::CustomError("no eth provider".to_owned())),
+        };
+        let era_provider = match &self.era_provider {
+            Some(era_provider) => era_provider,
+            None => return Err(ZKSWalletError::CustomError("no era provider".to_owned())),
+        };
+
+        let receipt =
+            era_provider
+                .get_transaction_receipt(l2_tx_hash)
+                .await?
+                .ok_or(ZKSWalletError::CustomError(
+                    "no transaction receipt for withdrawal".to_owned(),
+                ))?;
+
+        let proof = era_provider
+            .get_l2_to_l1_log_proof(l2_tx_hash, None)
             .await?
             .ok_or(ZKSWalletError::CustomError(
-                "no transaction receipt".to_owned(),
-            ))
+                "no L2->L1 log proof for withdrawal".to_owned(),
+            ))?;
+
+        let merkle_proof = Token::Array(
+            proof
+                .proof
+                .iter()
+                .map(|node| Token::FixedBytes(node.as_bytes().to_vec()))
+                .collect(),
+        );
+        let finalize_withdrawal_args = [
+            Token::Uint(receipt.block_number.unwrap_or_default().as_u64().into()),
+            Token::Uint(proof.id.into()),
+            Token::Uint(receipt.transaction_index.as_u64().into()),
+            Token::Bytes(Vec::new()),
+            merkle_proof,
+        ];
+
+        let (finalize_to, call_data) = if token.is_none() {
+            (
+                eth_provider.get_main_contract().await?,
+                encode_call(
+                    "finalizeEthWithdrawal(uint256,uint256,uint16,bytes,bytes32[])",
+                    &finalize_withdrawal_args,
+                ),
+            )
+        } else {
+            (
+                eth_provider.get_bridge_contracts().await?.l1_erc20_default_bridge,
+                encode_call(
+                    "finalizeWithdrawal(uint256,uint256,uint16,bytes,bytes32[])",
+                    &finalize_withdrawal_args,
+                ),
+            )
+        };
+
+        let finalize_request = Eip1559TransactionRequest::new()
+            .from(self.address())
+            .to(finalize_to)
+            .data(call_data)
+            .chain_id(ETH_CHAIN_ID);
+
+        let pending_transaction = eth_provider.send_transaction(finalize_request, None).await?;
+
+        self.wait_for_receipt(pending_transaction).await
     }
 }
 